@@ -0,0 +1,96 @@
+//! Shared, non-vectorized base64 constants and scalar reference symbols.
+//!
+//! The SIMD kernels in [`crate::impl_v128`] encode this same mapping as
+//! range-based arithmetic; this module is the scalar source of truth used
+//! by scalar fallbacks elsewhere in the crate.
+
+/// Which base64 alphabet variant to use.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Alphabet {
+    /// RFC 4648 §4 standard alphabet (`+`, `/`).
+    Standard,
+    /// RFC 4648 §5 URL- and filename-safe alphabet (`-`, `_`).
+    UrlSafe,
+}
+
+impl Alphabet {
+    /// The ASCII bytes used for 6-bit indices 62 and 63, in that order.
+    pub(crate) const fn last_two(self) -> (u8, u8) {
+        match self {
+            Self::Standard => (b'+', b'/'),
+            Self::UrlSafe => (b'-', b'_'),
+        }
+    }
+}
+
+/// Scalar encode of a single 6-bit index into its alphabet character.
+pub(crate) const fn encode_symbol(alphabet: Alphabet, index: u8) -> u8 {
+    match index {
+        0..=25 => index + b'A',
+        26..=51 => index - 26 + b'a',
+        52..=61 => index - 52 + b'0',
+        62 => alphabet.last_two().0,
+        _ => alphabet.last_two().1,
+    }
+}
+
+/// Scalar decode of a single alphabet character back to its 6-bit index,
+/// or `None` if `byte` is not part of `alphabet`.
+pub(crate) const fn decode_symbol(alphabet: Alphabet, byte: u8) -> Option<u8> {
+    let (plus, slash) = alphabet.last_two();
+    match byte {
+        b'A'..=b'Z' => Some(byte - b'A'),
+        b'a'..=b'z' => Some(byte - b'a' + 26),
+        b'0'..=b'9' => Some(byte - b'0' + 52),
+        b if b == plus => Some(62),
+        b if b == slash => Some(63),
+        _ => None,
+    }
+}
+
+/// Whether [`crate::encode_to`] emits trailing `=` padding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Padding {
+    /// Pad the output to a multiple of 4 bytes, per RFC 4648 §4.
+    Canonical,
+    /// Never emit padding, per RFC 4648 §3.2.
+    None,
+}
+
+/// How strictly [`crate::decode_to`] validates a non-canonical trailing
+/// partial group.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeMode {
+    /// Accept any combination of valid base64 symbols, including a
+    /// trailing partial group whose discarded bits are non-zero.
+    Lenient,
+    /// Reject input whose trailing partial group does not round-trip
+    /// exactly: a dangling single symbol, or discarded bits that are not
+    /// all zero.
+    Strict,
+}
+
+/// Why a [`crate::decode_to`] call failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeErrorKind {
+    /// A byte that is neither part of the alphabet nor `=` padding was
+    /// found at `offset`.
+    InvalidSymbol,
+    /// The input's length (with padding stripped) is congruent to 1 mod
+    /// 4, which cannot correspond to any base64 encoding.
+    InvalidLength,
+    /// [`DecodeMode::Strict`] rejected a non-canonical trailing partial
+    /// group whose discarded bits are non-zero. (A dangling symbol is
+    /// reported as [`DecodeErrorKind::InvalidLength`].)
+    NonCanonical,
+}
+
+/// A base64 decode failure, pinpointing the first offending byte.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecodeError {
+    pub kind: DecodeErrorKind,
+    /// Byte offset of the failure within the original input.
+    pub offset: usize,
+    /// The offending byte, when `kind` identifies one.
+    pub byte: Option<u8>,
+}
@@ -0,0 +1,19 @@
+use std::arch::wasm32::v128;
+
+use crate::base64::Alphabet;
+use crate::impl_v128;
+
+pub(crate) fn decode_chunk(ascii: &[u8; 16], alphabet: Alphabet) -> Result<v128, ()> {
+    impl_v128::decode_chunk(ascii, alphabet)
+}
+
+/// Constant-time variant of [`decode_chunk`]: see
+/// [`impl_v128::decode_chunk_ct`].
+pub(crate) fn decode_chunk_ct(ascii: &[u8; 16], alphabet: Alphabet) -> (v128, bool) {
+    impl_v128::decode_chunk_ct(ascii, alphabet)
+}
+
+/// Number of raw bytes decoded from `len` base64 ASCII bytes (no padding).
+pub(crate) const fn decoded_len(len: usize) -> usize {
+    len * 3 / 4
+}
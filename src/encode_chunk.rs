@@ -0,0 +1,16 @@
+use std::arch::wasm32::v128;
+
+use crate::base64::Alphabet;
+use crate::impl_v128;
+
+pub(crate) fn encode_chunk(chunk: &[u8; 16], alphabet: Alphabet) -> v128 {
+    impl_v128::encode_chunk(chunk, alphabet)
+}
+
+/// Number of *meaningful* base64 ASCII bytes produced by encoding `len`
+/// input bytes, i.e. excluding any trailing `=` padding. Callers that
+/// need padding accounted for should add it separately (see
+/// [`crate::encode_to`]).
+pub(crate) const fn encoded_len(len: usize) -> usize {
+    (len * 4 + 2) / 3
+}
@@ -0,0 +1,149 @@
+//! `v128`-vectorized base64 encode/decode kernels.
+//!
+//! Each kernel processes one 16-byte lane at a time: 12 input bytes for
+//! encode, 16 ascii bytes for decode. Rather than a lookup table, every
+//! 6-bit index is mapped to (or from) ASCII by comparing it against range
+//! boundaries and selecting a per-range additive offset with
+//! [`v128_bitselect`], so the inner loop stays branchless.
+
+use std::arch::wasm32::*;
+
+use crate::base64::Alphabet;
+
+/// Encodes the first 12 bytes of `chunk` (the trailing 4 bytes are
+/// ignored) into a 16-byte ASCII base64 block.
+pub fn encode_chunk(chunk: &[u8; 16], alphabet: Alphabet) -> v128 {
+    let mut indices = [0u8; 16];
+    for i in 0..4 {
+        let b0 = chunk[i * 3];
+        let b1 = chunk[i * 3 + 1];
+        let b2 = chunk[i * 3 + 2];
+        indices[i * 4] = b0 >> 2;
+        indices[i * 4 + 1] = ((b0 & 0x03) << 4) | (b1 >> 4);
+        indices[i * 4 + 2] = ((b1 & 0x0f) << 2) | (b2 >> 6);
+        indices[i * 4 + 3] = b2 & 0x3f;
+    }
+
+    let indices = unsafe { v128_load(indices.as_ptr().cast()) };
+    translate(indices, alphabet)
+}
+
+/// Decodes a 16-byte ASCII base64 block into 12 raw bytes (returned as the
+/// low 12 bytes of the result). Fails if any of the 16 input bytes is not
+/// part of `alphabet`.
+pub fn decode_chunk(ascii: &[u8; 16], alphabet: Alphabet) -> Result<v128, ()> {
+    let (decoded, valid) = decode_chunk_ct(ascii, alphabet);
+    if valid { Ok(decoded) } else { Err(()) }
+}
+
+/// Constant-time variant of [`decode_chunk`]: always computes the decoded
+/// bytes and returns them alongside a validity flag, rather than
+/// early-returning once an invalid byte is found. Callers handling
+/// secret-bearing input should fold the flag into a single mask across
+/// every chunk and branch on it only once, after all chunks have been
+/// decoded the same way.
+pub fn decode_chunk_ct(ascii: &[u8; 16], alphabet: Alphabet) -> (v128, bool) {
+    let input = unsafe { v128_load(ascii.as_ptr().cast()) };
+    let (indices, valid) = untranslate(input, alphabet);
+
+    let mut idx = [0u8; 16];
+    unsafe { v128_store(idx.as_mut_ptr().cast(), indices) };
+
+    let mut out = [0u8; 16];
+    for i in 0..4 {
+        let i0 = idx[i * 4];
+        let i1 = idx[i * 4 + 1];
+        let i2 = idx[i * 4 + 2];
+        let i3 = idx[i * 4 + 3];
+        out[i * 3] = (i0 << 2) | (i1 >> 4);
+        out[i * 3 + 1] = (i1 << 4) | (i2 >> 2);
+        out[i * 3 + 2] = (i2 << 6) | i3;
+    }
+
+    let decoded = unsafe { v128_load(out.as_ptr().cast()) };
+    (decoded, u8x16_bitmask(valid) == 0xffff)
+}
+
+/// Maps each 6-bit index (0..=63) in `indices` to its ASCII symbol under
+/// `alphabet` by adding a range-dependent offset: indices 0..=25 get
+/// `+65`, 26..=51 get `+71`, 52..=61 get `-4`, 62 and 63 get whatever
+/// offset lands on the alphabet's two non-alphanumeric symbols.
+fn translate(indices: v128, alphabet: Alphabet) -> v128 {
+    let (sym62, sym63) = alphabet.last_two();
+
+    let offset_0_25 = u8x16_splat(65);
+    let offset_26_51 = u8x16_splat(71);
+    let offset_52_61 = u8x16_splat(48u8.wrapping_sub(52));
+    let offset_62 = u8x16_splat(sym62.wrapping_sub(62));
+    let offset_63 = u8x16_splat(sym63.wrapping_sub(63));
+
+    let lt_26 = u8x16_lt(indices, u8x16_splat(26));
+    let lt_52 = u8x16_lt(indices, u8x16_splat(52));
+    let lt_62 = u8x16_lt(indices, u8x16_splat(62));
+    let eq_62 = u8x16_eq(indices, u8x16_splat(62));
+
+    let offset = v128_bitselect(
+        offset_0_25,
+        v128_bitselect(
+            offset_26_51,
+            v128_bitselect(
+                offset_52_61,
+                v128_bitselect(offset_62, offset_63, eq_62),
+                lt_62,
+            ),
+            lt_52,
+        ),
+        lt_26,
+    );
+
+    u8x16_add(indices, offset)
+}
+
+/// Inverse of [`translate`]: recovers each byte's 6-bit index plus a
+/// per-byte validity mask (all-ones lane if the byte belongs to
+/// `alphabet`, all-zeros otherwise).
+fn untranslate(ascii: v128, alphabet: Alphabet) -> (v128, v128) {
+    let (sym62, sym63) = alphabet.last_two();
+
+    let is_upper = v128_and(
+        u8x16_ge(ascii, u8x16_splat(b'A')),
+        u8x16_le(ascii, u8x16_splat(b'Z')),
+    );
+    let is_lower = v128_and(
+        u8x16_ge(ascii, u8x16_splat(b'a')),
+        u8x16_le(ascii, u8x16_splat(b'z')),
+    );
+    let is_digit = v128_and(
+        u8x16_ge(ascii, u8x16_splat(b'0')),
+        u8x16_le(ascii, u8x16_splat(b'9')),
+    );
+    let is_62 = u8x16_eq(ascii, u8x16_splat(sym62));
+    let is_63 = u8x16_eq(ascii, u8x16_splat(sym63));
+
+    let valid = v128_or(
+        v128_or(v128_or(is_upper, is_lower), is_digit),
+        v128_or(is_62, is_63),
+    );
+
+    let offset_upper = u8x16_splat(0u8.wrapping_sub(b'A'));
+    let offset_lower = u8x16_splat(26u8.wrapping_sub(b'a'));
+    let offset_digit = u8x16_splat(52u8.wrapping_sub(b'0'));
+    let offset_62 = u8x16_splat(62u8.wrapping_sub(sym62));
+    let offset_63 = u8x16_splat(63u8.wrapping_sub(sym63));
+
+    let offset = v128_bitselect(
+        offset_upper,
+        v128_bitselect(
+            offset_lower,
+            v128_bitselect(
+                offset_digit,
+                v128_bitselect(offset_62, offset_63, is_62),
+                is_digit,
+            ),
+            is_lower,
+        ),
+        is_upper,
+    );
+
+    (u8x16_add(ascii, offset), valid)
+}
@@ -1,20 +1,24 @@
 #![warn(clippy::nursery)]
 #![cfg(target_arch = "wasm32")]
 
-use std::arch::wasm32::v128;
+use std::arch::wasm32::{v128, v128_store};
 use std::slice;
 
+use js_sys::{Object, Reflect};
 use wasm_bindgen::JsValue;
 use wasm_bindgen::prelude::wasm_bindgen;
 
-use decode_chunk::{decode_chunk, decoded_len};
-use encode_chunk::{encode_chunk, encoded_len};
+use base64::{Alphabet, DecodeError, DecodeErrorKind, DecodeMode, Padding};
+use decode_chunk::decode_chunk;
+use encode_chunk::encode_chunk;
 
 mod base64;
 mod decode_chunk;
 mod encode_chunk;
-mod fuzz;
 pub mod impl_v128;
+mod stream;
+
+pub use stream::{Base64Decoder, Base64Encoder};
 
 /// [`atob`] decodes a string of data from an ascii string.
 #[wasm_bindgen]
@@ -28,11 +32,13 @@ pub fn btoa(binary_string: String) -> Result<String, JsValue> {
     encode_to_utf8(binary_string.as_bytes())
 }
 
-/// [`encode`] converts bytes into a base64-encoded byte array.
+/// [`encode`] converts bytes into a base64-encoded byte array using the
+/// standard alphabet (`+`, `/`).
 #[wasm_bindgen]
 pub fn encode(data: &[u8]) -> Result<Vec<u8>, JsValue> {
     let mut ascii = Vec::new();
-    encode_to(data, &mut ascii).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    encode_to(data, &mut ascii, Alphabet::Standard, Padding::Canonical)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
     Ok(ascii)
 }
 
@@ -41,20 +47,264 @@ pub fn encode_to_utf8(data: &[u8]) -> Result<String, JsValue> {
     Ok(unsafe { String::from_utf8_unchecked(encode(data)?) })
 }
 
-/// [`decode`] takes ascii and returns its original binary representation.
+/// [`encode_unpadded`] converts bytes into a base64-encoded byte array
+/// using the standard alphabet, without trailing `=` padding.
+#[wasm_bindgen]
+pub fn encode_unpadded(data: &[u8]) -> Result<Vec<u8>, JsValue> {
+    let mut ascii = Vec::new();
+    encode_to(data, &mut ascii, Alphabet::Standard, Padding::None)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(ascii)
+}
+
+/// [`decode`] takes ascii encoded with the standard alphabet and returns
+/// its original binary representation. Accepts both padded and unpadded
+/// input, and ignores non-canonical discarded bits in a trailing partial
+/// group; use [`decode_strict`] to reject those.
 #[wasm_bindgen]
 pub fn decode(ascii: &[u8]) -> Result<Vec<u8>, JsValue> {
     let mut data = Vec::new();
-    decode_to(ascii, &mut data)?;
+    decode_to(ascii, &mut data, Alphabet::Standard, DecodeMode::Lenient)?;
+    Ok(data)
+}
+
+/// Like [`decode`], but errors if the input's trailing partial group does
+/// not round-trip exactly (a dangling symbol, or non-zero discarded bits).
+#[wasm_bindgen]
+pub fn decode_strict(ascii: &[u8]) -> Result<Vec<u8>, JsValue> {
+    let mut data = Vec::new();
+    decode_to(ascii, &mut data, Alphabet::Standard, DecodeMode::Strict)?;
     Ok(data)
 }
 
-fn encode_to(data: &[u8], out: &mut Vec<u8>) -> Result<(), String> {
+/// [`encode_url`] converts bytes into a base64-encoded byte array using
+/// the URL- and filename-safe alphabet (`-`, `_`).
+#[wasm_bindgen]
+pub fn encode_url(data: &[u8]) -> Result<Vec<u8>, JsValue> {
+    let mut ascii = Vec::new();
+    encode_to(data, &mut ascii, Alphabet::UrlSafe, Padding::Canonical)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(ascii)
+}
+
+/// [`decode_url`] takes ascii encoded with the URL-safe alphabet and
+/// returns its original binary representation.
+#[wasm_bindgen]
+pub fn decode_url(ascii: &[u8]) -> Result<Vec<u8>, JsValue> {
+    let mut data = Vec::new();
+    decode_to(ascii, &mut data, Alphabet::UrlSafe, DecodeMode::Lenient)?;
+    Ok(data)
+}
+
+/// Total base64 ASCII bytes needed to encode `len` input bytes, including
+/// canonical `=` padding. Sized for [`encode_into`].
+#[wasm_bindgen]
+pub fn encoded_len(len: usize) -> usize {
+    let real = encode_chunk::encoded_len(len);
+    real + (4 - real % 4) % 4
+}
+
+/// Raw bytes decoded from `len` base64 ASCII bytes (padding already
+/// stripped). Sized for [`decode_into`].
+#[wasm_bindgen]
+pub fn decoded_len(len: usize) -> usize {
+    decode_chunk::decoded_len(len)
+}
+
+/// Encodes `data` into the caller-provided `out` buffer using the standard
+/// alphabet with canonical padding, writing nothing to the heap. Returns
+/// the number of bytes written, which is always `encoded_len(data.len())`;
+/// `out` must be at least that long.
+#[wasm_bindgen]
+pub fn encode_into(data: &[u8], out: &mut [u8]) -> Result<usize, JsValue> {
+    if data.is_empty() {
+        return Err(JsValue::from_str("empty data"));
+    }
+
+    let needed = encoded_len(data.len());
+    if out.len() < needed {
+        return Err(JsValue::from_str("destination buffer too small"));
+    }
+
+    let mut pos = 0;
+    let mut chunks = data.chunks_exact(12);
+
+    for chunk in &mut chunks {
+        let mut padded = [0u8; 16];
+        padded[..12].copy_from_slice(chunk);
+
+        let encoded = encode_chunk(&padded, Alphabet::Standard);
+        write_block(&mut out[pos..], encoded, 16);
+        pos += 16;
+    }
+
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        let mut padded = [0u8; 16];
+        padded[..tail.len()].copy_from_slice(tail);
+
+        let encoded = encode_chunk(&padded, Alphabet::Standard);
+        let real = encode_chunk::encoded_len(tail.len());
+        write_block(&mut out[pos..], encoded, real);
+        pos += real;
+    }
+
+    out[pos..needed].fill(b'=');
+
+    Ok(needed)
+}
+
+/// Decodes `ascii` (standard alphabet, lenient) into the caller-provided
+/// `out` buffer, writing nothing to the heap. Returns the number of bytes
+/// written; `out` must be at least `decoded_len(ascii.len())` long.
+#[wasm_bindgen]
+pub fn decode_into(ascii: &[u8], out: &mut [u8]) -> Result<usize, JsValue> {
+    let ascii = strip_padding(ascii);
+
+    if ascii.is_empty() {
+        return Ok(0);
+    }
+
+    if ascii.len() % 4 == 1 {
+        return Err(DecodeError {
+            kind: DecodeErrorKind::InvalidLength,
+            offset: ascii.len() - 1,
+            byte: None,
+        }
+        .into());
+    }
+
+    let needed = decode_chunk::decoded_len(ascii.len());
+    if out.len() < needed {
+        return Err(JsValue::from_str("destination buffer too small"));
+    }
+
+    let mut pos = 0;
+    let mut chunks = ascii.chunks_exact(16);
+
+    for (i, chunk) in (&mut chunks).enumerate() {
+        let block: &[u8; 16] = chunk.try_into().expect("Slice with incorrect length");
+        let decoded = decode_chunk(block, Alphabet::Standard)
+            .map_err(|()| locate_invalid_symbol(chunk, i * 16, Alphabet::Standard))?;
+
+        write_block(&mut out[pos..], decoded, 12);
+        pos += 12;
+    }
+
+    let rest = chunks.remainder();
+    if !rest.is_empty() {
+        let block_start = ascii.len() - rest.len();
+        let mut padded = [b'A'; 16];
+        padded[..rest.len()].copy_from_slice(rest);
+
+        let decoded = decode_chunk(&padded, Alphabet::Standard)
+            .map_err(|()| locate_invalid_symbol(rest, block_start, Alphabet::Standard))?;
+
+        let real = decode_chunk::decoded_len(rest.len());
+        write_block(&mut out[pos..], decoded, real);
+        pos += real;
+    }
+
+    Ok(pos)
+}
+
+/// Writes the first `real` bytes of a `v128` block into `out`. Takes the
+/// unaligned full-width store when `out` has 16 bytes of room, and
+/// otherwise falls back to a bounds-safe scalar copy so the don't-care
+/// trailing lanes never spill past `out`'s end.
+fn write_block(out: &mut [u8], block: v128, real: usize) {
+    if out.len() >= 16 {
+        unsafe { out.as_mut_ptr().cast::<v128>().write_unaligned(block) };
+    } else {
+        let mut bytes = [0u8; 16];
+        unsafe { v128_store(bytes.as_mut_ptr().cast(), block) };
+        out[..real].copy_from_slice(&bytes[..real]);
+    }
+}
+
+/// Strips up to two trailing `=` padding bytes from `ascii`, if present.
+fn strip_padding(ascii: &[u8]) -> &[u8] {
+    match ascii {
+        [p @ .., b'=', b'='] | [p @ .., b'='] | p => p,
+    }
+}
+
+/// Decodes `ascii` (standard alphabet) in constant time, for secret-bearing
+/// input such as keys or tokens. Every chunk is decoded unconditionally
+/// and its validity folded into a single mask; control flow never depends
+/// on the decoded bytes, and the result is converted to a `Result` only
+/// once, at the very end. On failure the output buffer is zeroed before
+/// the error is returned, and (unlike [`decode`]) the error carries no
+/// offset, since pinpointing the failure would itself leak which byte of
+/// the secret was malformed.
+#[wasm_bindgen]
+pub fn decode_ct(ascii: &[u8]) -> Result<Vec<u8>, JsValue> {
+    let ascii = strip_padding(ascii);
+
+    if ascii.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    if ascii.len() % 4 == 1 {
+        return Err(JsValue::from_str("invalid input"));
+    }
+
+    let mut out = vec![0u8; decode_chunk::decoded_len(ascii.len())];
+    let mut valid = true;
+    let mut pos = 0;
+
+    let mut chunks = ascii.chunks_exact(16);
+    for chunk in &mut chunks {
+        let block: &[u8; 16] = chunk.try_into().expect("Slice with incorrect length");
+        let (decoded, chunk_valid) = decode_chunk::decode_chunk_ct(block, Alphabet::Standard);
+        valid &= chunk_valid;
+
+        write_block(&mut out[pos..], decoded, 12);
+        pos += 12;
+    }
+
+    let rest = chunks.remainder();
+    if !rest.is_empty() {
+        let mut padded = [b'A'; 16];
+        padded[..rest.len()].copy_from_slice(rest);
+
+        let (decoded, chunk_valid) = decode_chunk::decode_chunk_ct(&padded, Alphabet::Standard);
+        valid &= chunk_valid;
+
+        let real = decode_chunk::decoded_len(rest.len());
+        write_block(&mut out[pos..], decoded, real);
+    }
+
+    if valid {
+        Ok(out)
+    } else {
+        zeroize(&mut out);
+        Err(JsValue::from_str("invalid input"))
+    }
+}
+
+/// Overwrites every byte of `buf` with zero via volatile writes. Used to
+/// clear secret-bearing output on a [`decode_ct`] failure; a plain
+/// `iter_mut().for_each(|b| *b = 0)` would be a dead store the optimizer
+/// is free to elide, since `buf` is dropped immediately afterward with no
+/// further read.
+fn zeroize(buf: &mut [u8]) {
+    for byte in buf.iter_mut() {
+        unsafe { std::ptr::write_volatile(byte, 0) };
+    }
+}
+
+pub(crate) fn encode_to(
+    data: &[u8],
+    out: &mut Vec<u8>,
+    alphabet: Alphabet,
+    padding: Padding,
+) -> Result<(), String> {
     if data.is_empty() {
         return Err(String::from("empty data"));
     }
 
-    out.reserve(encoded_len(data.len()) + 16);
+    out.reserve(encode_chunk::encoded_len(data.len()) + 16);
     let mut raw_out = out.as_mut_ptr_range().end;
 
     let mut start = data.as_ptr();
@@ -71,7 +321,7 @@ fn encode_to(data: &[u8], out: &mut Vec<u8>) -> Result<(), String> {
     while start != end {
         let chunk = unsafe { slice::from_raw_parts(start, 16) };
         let chunk: &[u8; 16] = chunk.try_into().expect("Slice with incorrect length");
-        let encoded = encode_chunk(chunk);
+        let encoded = encode_chunk(chunk, alphabet);
 
         unsafe {
             start = start.add(12);
@@ -91,13 +341,13 @@ fn encode_to(data: &[u8], out: &mut Vec<u8>) -> Result<(), String> {
         let mut temp_chunk = [0u8; 16];
         temp_chunk[0..chunk.len()].copy_from_slice(chunk);
 
-        let encoded = encode_chunk(&temp_chunk);
+        let encoded = encode_chunk(&temp_chunk, alphabet);
 
         unsafe {
             start = start.add(chunk.len());
 
             raw_out.cast::<v128>().write_unaligned(encoded);
-            raw_out = raw_out.add(encoded_len(chunk.len()));
+            raw_out = raw_out.add(encode_chunk::encoded_len(chunk.len()));
         }
     }
 
@@ -106,35 +356,50 @@ fn encode_to(data: &[u8], out: &mut Vec<u8>) -> Result<(), String> {
         out.set_len(new_len as usize);
     }
 
-    match out.len() % 4 {
-        2 => out.extend_from_slice(b"=="),
-        3 => out.extend_from_slice(b"="),
-        _ => {}
+    if padding == Padding::Canonical {
+        match out.len() % 4 {
+            2 => out.extend_from_slice(b"=="),
+            3 => out.extend_from_slice(b"="),
+            _ => {}
+        }
     }
 
     Ok(())
 }
 
-pub fn decode_to(data: &[u8], out: &mut Vec<u8>) -> Result<(), String> {
-    let data = match data {
-        [p @ .., b'=', b'='] | [p @ .., b'='] | p => p,
-    };
+pub fn decode_to(
+    data: &[u8],
+    out: &mut Vec<u8>,
+    alphabet: Alphabet,
+    mode: DecodeMode,
+) -> Result<(), DecodeError> {
+    let data = strip_padding(data);
 
     if data.is_empty() {
         return Ok(());
     }
 
-    out.reserve(decoded_len(data.len()) + 16);
+    if data.len() % 4 == 1 {
+        return Err(DecodeError {
+            kind: DecodeErrorKind::InvalidLength,
+            offset: data.len() - 1,
+            byte: None,
+        });
+    }
+
+    if mode == DecodeMode::Strict {
+        check_strict_tail(data, alphabet)?;
+    }
+
+    out.reserve(decode_chunk::decoded_len(data.len()) + 16);
     let mut raw_out = out.as_mut_ptr_range().end;
 
     let mut chunks = data.chunks_exact(16);
-    let mut failed = false;
 
-    for chunk in &mut chunks {
-        let ascii = chunk.try_into().expect("Slice with incorrect length");
-        let decoded = decode_chunk(ascii);
-        failed |= decoded.is_err();
-        let decoded = decoded.unwrap();
+    for (i, chunk) in (&mut chunks).enumerate() {
+        let ascii: &[u8; 16] = chunk.try_into().expect("Slice with incorrect length");
+        let decoded =
+            decode_chunk(ascii, alphabet).map_err(|()| locate_invalid_symbol(chunk, i * 16, alphabet))?;
 
         unsafe {
             raw_out.cast::<v128>().write_unaligned(decoded);
@@ -144,20 +409,20 @@ pub fn decode_to(data: &[u8], out: &mut Vec<u8>) -> Result<(), String> {
 
     let rest = chunks.remainder();
     if !rest.is_empty() {
+        let block_start = data.len() - rest.len();
         let mut ascii = [b'A'; 16];
         ascii[0..rest.len()].copy_from_slice(rest);
-        let decoded = decode_chunk(&ascii);
-        failed |= decoded.is_err();
-        let decoded = decoded.unwrap();
+        let decoded = decode_chunk(&ascii, alphabet)
+            .map_err(|()| locate_invalid_symbol(rest, block_start, alphabet))?;
 
         unsafe {
             raw_out.cast::<v128>().write_unaligned(decoded);
-            raw_out = raw_out.add(decoded_len(rest.len()));
+            raw_out = raw_out.add(decode_chunk::decoded_len(rest.len()));
         }
     }
 
-    if failed {
-        return Err(String::from("the decoding process failed unexpectedly"));
+    if mode == DecodeMode::Strict {
+        check_strict_tail(data, alphabet)?;
     }
 
     unsafe {
@@ -167,6 +432,87 @@ pub fn decode_to(data: &[u8], out: &mut Vec<u8>) -> Result<(), String> {
 
     Ok(())
 }
+
+/// Rejects a trailing partial base64 group that does not round-trip
+/// exactly: a final symbol whose bits beyond the decoded byte boundary
+/// are not all zero. The dangling-single-symbol case is already rejected
+/// unconditionally by [`decode_to`] as an invalid length.
+fn check_strict_tail(data: &[u8], alphabet: Alphabet) -> Result<(), DecodeError> {
+    let tail_len = data.len() % 4;
+    if tail_len == 0 {
+        return Ok(());
+    }
+
+    let last_offset = data.len() - 1;
+    let last_byte = data[last_offset];
+    let last_index = base64::decode_symbol(alphabet, last_byte).ok_or(DecodeError {
+        kind: DecodeErrorKind::InvalidSymbol,
+        offset: last_offset,
+        byte: Some(last_byte),
+    })?;
+
+    let discarded = if tail_len == 2 {
+        last_index & 0x0f
+    } else {
+        last_index & 0x03
+    };
+
+    if discarded == 0 {
+        Ok(())
+    } else {
+        Err(DecodeError {
+            kind: DecodeErrorKind::NonCanonical,
+            offset: last_offset,
+            byte: Some(last_byte),
+        })
+    }
+}
+
+/// When the SIMD `decode_chunk` fast path rejects `block`, falls back to
+/// a scalar scan to pinpoint the first byte that is not part of
+/// `alphabet`, reporting its absolute offset within the original input.
+pub(crate) fn locate_invalid_symbol(block: &[u8], block_start: usize, alphabet: Alphabet) -> DecodeError {
+    for (j, &byte) in block.iter().enumerate() {
+        if base64::decode_symbol(alphabet, byte).is_none() {
+            return DecodeError {
+                kind: DecodeErrorKind::InvalidSymbol,
+                offset: block_start + j,
+                byte: Some(byte),
+            };
+        }
+    }
+
+    // The SIMD and scalar paths disagreed; this should not happen, but
+    // report the block's first byte rather than panicking.
+    DecodeError {
+        kind: DecodeErrorKind::InvalidSymbol,
+        offset: block_start,
+        byte: block.first().copied(),
+    }
+}
+
+impl From<DecodeError> for JsValue {
+    fn from(err: DecodeError) -> Self {
+        let kind = match err.kind {
+            DecodeErrorKind::InvalidSymbol => "invalid_symbol",
+            DecodeErrorKind::InvalidLength => "invalid_length",
+            DecodeErrorKind::NonCanonical => "non_canonical",
+        };
+
+        let object = Object::new();
+        let byte = err.byte.map_or(JsValue::UNDEFINED, |b| JsValue::from_f64(f64::from(b)));
+        Reflect::set(&object, &JsValue::from_str("kind"), &JsValue::from_str(kind)).unwrap();
+        Reflect::set(
+            &object,
+            &JsValue::from_str("offset"),
+            &JsValue::from_f64(err.offset as f64),
+        )
+        .unwrap();
+        Reflect::set(&object, &JsValue::from_str("byte"), &byte).unwrap();
+
+        object.into()
+    }
+}
 #[cfg(test)]
 mod tests {
     use wasm_bindgen_test::wasm_bindgen_test;
@@ -179,15 +525,52 @@ mod tests {
         let raw_data = b"Hello World";
 
         let mut out = Vec::new();
-        decode_to(encoded_data, &mut out)?;
+        decode_to(encoded_data, &mut out, Alphabet::Standard, DecodeMode::Lenient)?;
         assert_eq!(out, raw_data);
 
         out = Vec::new();
-        encode_to(raw_data, &mut out)?;
+        encode_to(raw_data, &mut out, Alphabet::Standard, Padding::Canonical)?;
         assert_eq!(out, encoded_data);
         Ok(())
     }
 
+    #[wasm_bindgen_test]
+    fn test_decode_reports_invalid_symbol_offset() {
+        let mut out = Vec::new();
+        let err = decode_to(b"SGVs!G8=", &mut out, Alphabet::Standard, DecodeMode::Lenient)
+            .unwrap_err();
+        assert_eq!(err.kind, DecodeErrorKind::InvalidSymbol);
+        assert_eq!(err.offset, 4);
+        assert_eq!(err.byte, Some(b'!'));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_decode_reports_invalid_length() {
+        let mut out = Vec::new();
+        let err = decode_to(b"SGVsbG8gV", &mut out, Alphabet::Standard, DecodeMode::Lenient)
+            .unwrap_err();
+        assert_eq!(err.kind, DecodeErrorKind::InvalidLength);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_unpadded_round_trip() -> Result<(), JsValue> {
+        let raw_data = b"Hello World";
+
+        let ascii = encode_unpadded(raw_data)?;
+        assert_eq!(ascii, b"SGVsbG8gV29ybGQ");
+
+        assert_eq!(decode(&ascii)?, raw_data);
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_decode_strict_rejects_non_canonical_padding() {
+        // "SGVsbG8=" is the canonical encoding of "Hello"; swapping the
+        // last symbol for one with non-zero discarded bits must error.
+        assert!(decode_strict(b"SGVsbG9=").is_err());
+        assert!(decode_strict(b"SGVsbG8=").is_ok());
+    }
+
     #[wasm_bindgen_test]
     fn test_readme_example() -> Result<(), JsValue> {
         let ascii = b"VGhlIGRvZyBsaWNrZWQgdGhlIG9pbCwgYW5kIGV2ZXJ5Ym9keSBsYXVnaGVkLg==";
@@ -199,4 +582,62 @@ mod tests {
 
         Ok(())
     }
+
+    #[wasm_bindgen_test]
+    fn test_url_safe_round_trip() -> Result<(), JsValue> {
+        // Chosen so the standard encoding would contain `+` and `/`.
+        let raw_data: &[u8] = &[0xfb, 0xff, 0xbf];
+
+        let ascii = encode_url(raw_data)?;
+        assert_eq!(ascii, b"-_-_");
+
+        let decoded = decode_url(&ascii)?;
+        assert_eq!(decoded, raw_data);
+
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encode_into_decode_into_round_trip() -> Result<(), JsValue> {
+        let raw_data = b"Hello World";
+
+        let mut ascii = vec![0u8; encoded_len(raw_data.len())];
+        let written = encode_into(raw_data, &mut ascii)?;
+        assert_eq!(written, ascii.len());
+        assert_eq!(ascii, b"SGVsbG8gV29ybGQ=");
+
+        let mut decoded = vec![0u8; decoded_len(ascii.len())];
+        let written = decode_into(&ascii, &mut decoded)?;
+        decoded.truncate(written);
+        assert_eq!(decoded, raw_data);
+
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encode_into_rejects_undersized_buffer() {
+        let mut out = vec![0u8; 3];
+        assert!(encode_into(b"Hello World", &mut out).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_decode_ct_matches_decode() -> Result<(), JsValue> {
+        let ascii = b"SGVsbG8gV29ybGQ=";
+        assert_eq!(decode_ct(ascii)?, decode(ascii)?);
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_decode_ct_rejects_invalid_input() {
+        // "SGVs!G8=" carries one invalid symbol at offset 4.
+        let err = decode_ct(b"SGVs!G8=").unwrap_err();
+        assert!(err.as_string().is_some());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_zeroize_clears_buffer() {
+        let mut buf = vec![1u8, 2, 3, 4, 5];
+        zeroize(&mut buf);
+        assert_eq!(buf, vec![0u8; 5]);
+    }
 }
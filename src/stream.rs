@@ -0,0 +1,223 @@
+//! Incremental encode/decode for chunked input, so callers don't need to
+//! buffer an entire payload in WASM memory before processing it.
+
+use std::arch::wasm32::v128_store;
+
+use wasm_bindgen::JsValue;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::base64::{Alphabet, DecodeError, DecodeMode, Padding};
+use crate::{decode_chunk, decode_to, encode_chunk, encode_to, locate_invalid_symbol};
+
+/// Encodes input fed incrementally via [`Base64Encoder::update`], emitting
+/// padding only once [`Base64Encoder::finalize`] is called.
+#[wasm_bindgen]
+pub struct Base64Encoder {
+    /// Leftover input bytes not yet a multiple of 12 (so not yet a whole
+    /// number of `v128` chunks); at most 11 bytes.
+    carry: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl Base64Encoder {
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self { carry: Vec::new() }
+    }
+
+    /// Encodes as much of `data` (plus anything carried over from the
+    /// previous call) as lands on a 12-byte boundary, returning the
+    /// unpadded ASCII produced. The remainder is retained for the next
+    /// `update` or for `finalize`.
+    pub fn update(&mut self, data: &[u8]) -> Vec<u8> {
+        self.carry.extend_from_slice(data);
+
+        let aligned = self.carry.len() - self.carry.len() % 12;
+        let mut out = Vec::with_capacity(aligned / 12 * 16);
+
+        for chunk in self.carry[..aligned].chunks_exact(12) {
+            let mut padded = [0u8; 16];
+            padded[..12].copy_from_slice(chunk);
+
+            let encoded = encode_chunk::encode_chunk(&padded, Alphabet::Standard);
+            let mut ascii = [0u8; 16];
+            unsafe { v128_store(ascii.as_mut_ptr().cast(), encoded) };
+            out.extend_from_slice(&ascii);
+        }
+
+        self.carry.drain(..aligned);
+        out
+    }
+
+    /// Encodes the final, possibly partial, carried-over bytes, with
+    /// canonical `=` padding.
+    pub fn finalize(self) -> Result<Vec<u8>, JsValue> {
+        if self.carry.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut out = Vec::new();
+        encode_to(&self.carry, &mut out, Alphabet::Standard, Padding::Canonical)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(out)
+    }
+}
+
+/// Decodes input fed incrementally via [`Base64Decoder::update`].
+#[wasm_bindgen]
+pub struct Base64Decoder {
+    /// Leftover ascii bytes not yet processed: anything not a multiple of
+    /// 16, plus the last aligned 16-byte block, which is always held
+    /// back (see [`update`](Self::update)); at most 31 bytes.
+    carry: Vec<u8>,
+    /// Ascii bytes already consumed and dropped from `carry` in previous
+    /// `update` calls, so that a [`DecodeError::offset`] reported from
+    /// this or a later call is an absolute position in the full stream
+    /// fed across all calls, not just the current call's local buffer.
+    total_consumed: usize,
+}
+
+#[wasm_bindgen]
+impl Base64Decoder {
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            carry: Vec::new(),
+            total_consumed: 0,
+        }
+    }
+
+    /// Decodes as much of `data` (plus anything carried over from the
+    /// previous call) as lands on a 16-byte boundary, returning the raw
+    /// bytes produced. The last aligned block is always held back, even
+    /// if `carry` is otherwise fully aligned: it may be the final,
+    /// padded group, and `=` is not a valid alphabet symbol for the raw
+    /// SIMD decode path, so only the padding-aware [`finalize`] can
+    /// decode it correctly. The remainder is retained for the next
+    /// `update` or for `finalize`.
+    ///
+    /// [`finalize`]: Self::finalize
+    pub fn update(&mut self, data: &[u8]) -> Result<Vec<u8>, JsValue> {
+        self.carry.extend_from_slice(data);
+
+        let aligned = (self.carry.len() - self.carry.len() % 16).saturating_sub(16);
+        let mut out = Vec::with_capacity(aligned / 16 * 12);
+
+        for (i, chunk) in self.carry[..aligned].chunks_exact(16).enumerate() {
+            let ascii: &[u8; 16] = chunk.try_into().expect("Slice with incorrect length");
+            let decoded = decode_chunk::decode_chunk(ascii, Alphabet::Standard).map_err(|()| {
+                locate_invalid_symbol(chunk, self.total_consumed + i * 16, Alphabet::Standard)
+            })?;
+
+            let mut bytes = [0u8; 16];
+            unsafe { v128_store(bytes.as_mut_ptr().cast(), decoded) };
+            out.extend_from_slice(&bytes[..12]);
+        }
+
+        self.carry.drain(..aligned);
+        self.total_consumed += aligned;
+        Ok(out)
+    }
+
+    /// Decodes the final, possibly partial (and possibly padded),
+    /// carried-over bytes.
+    pub fn finalize(self) -> Result<Vec<u8>, JsValue> {
+        if self.carry.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut out = Vec::new();
+        decode_to(&self.carry, &mut out, Alphabet::Standard, DecodeMode::Lenient).map_err(
+            |err| DecodeError {
+                offset: self.total_consumed + err.offset,
+                ..err
+            },
+        )?;
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+
+    #[wasm_bindgen_test]
+    fn test_streaming_encode_round_trip() -> Result<(), JsValue> {
+        let raw_data = b"Hello World, this spans more than one v128 chunk!";
+
+        let mut encoder = Base64Encoder::new();
+        let mut ascii = Vec::new();
+        for chunk in raw_data.chunks(5) {
+            ascii.extend_from_slice(&encoder.update(chunk));
+        }
+        ascii.extend_from_slice(&encoder.finalize()?);
+
+        let mut expected = Vec::new();
+        crate::encode_to(
+            raw_data,
+            &mut expected,
+            Alphabet::Standard,
+            Padding::Canonical,
+        )
+        .unwrap();
+        assert_eq!(ascii, expected);
+
+        let mut decoder = Base64Decoder::new();
+        let mut decoded = Vec::new();
+        for chunk in ascii.chunks(7) {
+            decoded.extend_from_slice(&decoder.update(chunk)?);
+        }
+        decoded.extend_from_slice(&decoder.finalize()?);
+        assert_eq!(decoded, raw_data);
+
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_streaming_decode_handles_padding_on_chunk_boundary() -> Result<(), JsValue> {
+        // "MDEyMzQ1Njc4OQ==" is exactly 16 bytes - a single full
+        // `Base64Decoder` chunk - and ends in `=` padding. Feeding it to
+        // one `update` call must not treat the padding as an invalid
+        // symbol; the aligned block has to be held back for `finalize`.
+        let raw_data = b"0123456789";
+        let ascii = b"MDEyMzQ1Njc4OQ==";
+
+        let mut decoder = Base64Decoder::new();
+        let mut decoded = decoder.update(ascii)?;
+        decoded.extend_from_slice(&decoder.finalize()?);
+
+        assert_eq!(decoded, raw_data);
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_update_reports_cumulative_offset_across_calls() {
+        let mut decoder = Base64Decoder::new();
+
+        // First call: 32 valid bytes; one aligned block is processed and
+        // dropped, advancing `total_consumed` past the start of this
+        // call's own buffer.
+        decoder.update(&[b'A'; 32]).unwrap();
+
+        // Second call: 16 more bytes with an invalid symbol at local
+        // index 5 (absolute stream offset 32 + 5 = 37). It lands in the
+        // block held back for the next call, so this one still succeeds.
+        let mut bad_chunk = [b'A'; 16];
+        bad_chunk[5] = b'!';
+        decoder.update(&bad_chunk).unwrap();
+
+        // Third call pushes the held-back bad block out of hold-back and
+        // into processing, surfacing the error with its absolute offset
+        // in the overall stream, not a position local to this call.
+        let err = decoder.update(&[b'A'; 16]).unwrap_err();
+        let offset = js_sys::Reflect::get(&err, &JsValue::from_str("offset"))
+            .unwrap()
+            .as_f64()
+            .unwrap();
+        assert_eq!(offset, 37.0);
+    }
+}